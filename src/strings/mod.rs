@@ -2,7 +2,7 @@
 
 // use alloc::vec::Vec;  // no-std
 
-use crate::number::UInt;
+use crate::number::{Float, UInt};
 
 /// Computes the Levenshtein distance between two strings.
 ///
@@ -56,7 +56,43 @@ use crate::number::UInt;
 /// * If the distance between `a` and `b` is too large to be represented by `U`.
 #[must_use]
 pub fn levenshtein<U: UInt>(a: &str, b: &str) -> U {
-    let (len_a, len_b) = (a.chars().count(), b.chars().count());
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    levenshtein_seq(&a, &b)
+}
+
+/// Computes the Levenshtein distance between two sequences of comparable
+/// elements.
+///
+/// This is the generic core behind [`levenshtein`]; it works over slices
+/// of any `PartialEq` element, so callers can compute edit distance over
+/// `&[u8]`, `&[u32]`, or arbitrary token slices (DNA, word tokens, event
+/// streams) without going through UTF-8.
+///
+/// # Arguments
+///
+/// * `a` - The first sequence.
+/// * `b` - The second sequence.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::levenshtein_seq;
+///
+/// let a = [1_u32, 2, 3, 4];
+/// let b = [1_u32, 3, 4];
+///
+/// let distance: u16 = levenshtein_seq(&a, &b);
+///
+/// assert_eq!(distance, 1);
+/// ```
+///
+/// # Panics
+///
+/// * If the distance between `a` and `b` is too large to be represented by `U`.
+#[must_use]
+pub fn levenshtein_seq<T: PartialEq, U: UInt>(a: &[T], b: &[T]) -> U {
+    let (len_a, len_b) = (a.len(), b.len());
 
     if len_a == 0 {
         // handle special case of 0 length
@@ -66,7 +102,7 @@ pub fn levenshtein<U: UInt>(a: &str, b: &str) -> U {
         U::from(len_a)
     } else if len_a < len_b {
         // require len_a < len_b
-        levenshtein(b, a)
+        levenshtein_seq(b, a)
     } else {
         let len_b = len_b + 1;
 
@@ -74,11 +110,11 @@ pub fn levenshtein<U: UInt>(a: &str, b: &str) -> U {
         let mut cur: Vec<usize> = (0..len_b).collect();
 
         // calculate edit distance
-        for (i, ca) in a.chars().enumerate() {
+        for (i, ca) in a.iter().enumerate() {
             // get first column for this row
             let mut pre = cur[0];
             cur[0] = i + 1;
-            for (j, cb) in b.chars().enumerate() {
+            for (j, cb) in b.iter().enumerate() {
                 let tmp = cur[j + 1];
                 cur[j + 1] = core::cmp::min(
                     // deletion
@@ -97,6 +133,329 @@ pub fn levenshtein<U: UInt>(a: &str, b: &str) -> U {
     }
 }
 
+/// Computes the Levenshtein distance between two strings, bailing out once
+/// it provably exceeds `max`.
+///
+/// When a caller only needs to know whether two strings are within some
+/// threshold of each other (spell-checking, deduplication), computing the
+/// full Wagner-Fischer table is wasteful. This variant returns `None` as
+/// soon as the distance is known to exceed `max`, and otherwise returns
+/// `Some(distance)`.
+///
+/// Two optimizations keep near-match queries cheap. First, shared leading
+/// and trailing characters are trimmed before running the DP, since they
+/// never contribute to the distance (as `rapidfuzz` does). Second, we use
+/// Ukkonen's banded idea: if the lengths differ by more than `max` the
+/// answer cannot be within the bound, and otherwise only the cells within
+/// `max` of the main diagonal are computed. After each row we check the
+/// smallest achievable value in the band and bail out if it already
+/// exceeds `max`, turning the work from `O(n * m)` into `O(n * max)`.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+/// * `max` - The largest distance the caller cares about.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::levenshtein_bounded;
+///
+/// let within: Option<u16> = levenshtein_bounded("kitten", "sitting", 3_u16);
+///
+/// assert_eq!(within, Some(3));
+///
+/// let beyond: Option<u16> = levenshtein_bounded("kitten", "sitting", 2_u16);
+///
+/// assert_eq!(beyond, None);
+/// ```
+///
+/// # References
+///
+/// * [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+#[must_use]
+pub fn levenshtein_bounded<U: UInt>(a: &str, b: &str, max: U) -> Option<U> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // Trim the shared prefix and suffix; they never affect the distance.
+    let mut start = 0;
+    while start < a.len() && start < b.len() && a[start] == b[start] {
+        start += 1;
+    }
+    let mut end = 0;
+    while end < a.len() - start && end < b.len() - start && a[a.len() - 1 - end] == b[b.len() - 1 - end]
+    {
+        end += 1;
+    }
+    let a = &a[start..a.len() - end];
+    let b = &b[start..b.len() - end];
+    let (len_a, len_b) = (a.len(), b.len());
+
+    let max_u = max.as_usize();
+
+    // If the lengths alone differ by more than `max`, no alignment fits.
+    if len_a.abs_diff(len_b) > max_u {
+        return None;
+    }
+
+    if len_a == 0 {
+        return Some(U::from(len_b));
+    } else if len_b == 0 {
+        return Some(U::from(len_a));
+    }
+
+    // Values at or beyond this marker are known to exceed the bound.
+    let inf = max_u.saturating_add(1);
+
+    let mut prev: Vec<usize> = vec![inf; len_b + 1];
+    for (j, cell) in prev.iter_mut().enumerate().take(core::cmp::min(max_u, len_b) + 1) {
+        *cell = j;
+    }
+    let mut cur: Vec<usize> = vec![inf; len_b + 1];
+
+    for i in 1..=len_a {
+        let lo = i.saturating_sub(max_u);
+        let hi = core::cmp::min(len_b, i + max_u);
+
+        let mut row_min = inf;
+        if lo == 0 {
+            cur[0] = i;
+            row_min = i;
+        } else {
+            // the reused buffer may hold a value from two rows back here
+            cur[lo - 1] = inf;
+        }
+        for j in core::cmp::max(lo, 1)..=hi {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let val = core::cmp::min(
+                // deletion
+                prev[j].saturating_add(1),
+                core::cmp::min(
+                    // insertion
+                    cur[j - 1].saturating_add(1),
+                    // match or substitution
+                    prev[j - 1].saturating_add(cost),
+                ),
+            );
+            cur[j] = val;
+            row_min = core::cmp::min(row_min, val);
+        }
+
+        // Every cell of the next band will be at least `row_min`.
+        if row_min > max_u {
+            return None;
+        }
+        core::mem::swap(&mut prev, &mut cur);
+    }
+
+    let distance = prev[len_b];
+    if distance > max_u {
+        None
+    } else {
+        Some(U::from(distance))
+    }
+}
+
+/// Computes the Optimal String Alignment distance between two strings.
+///
+/// Optimal String Alignment (OSA), sometimes called the restricted
+/// Damerau-Levenshtein distance, extends the Levenshtein distance by
+/// treating a transposition of two adjacent characters as a single edit.
+/// The restriction is that no substring may be edited more than once,
+/// which makes it cheaper to compute than the true Damerau-Levenshtein
+/// distance but means it does not satisfy the triangle inequality.
+///
+/// We extend the Wagner-Fischer recurrence: in addition to the deletion,
+/// insertion, and substitution candidates we keep the previous-previous
+/// row and, whenever `a[i-1] == b[j-2]` and `a[i-2] == b[j-1]`, add the
+/// transposition candidate `d[i-2][j-2] + 1` to the minimization.
+///
+/// The input strings are not required to be of the same length.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::osa_distance;
+///
+/// let distance: u16 = osa_distance("CA", "ABC");
+///
+/// assert_eq!(distance, 3);
+///
+/// let distance: u16 = osa_distance("ABC", "ACB");
+///
+/// assert_eq!(distance, 1);
+/// ```
+///
+/// # References
+///
+/// * [Damerau-Levenshtein distance](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance)
+///
+/// # Panics
+///
+/// * If the distance between `a` and `b` is too large to be represented by `U`.
+#[must_use]
+pub fn osa_distance<U: UInt>(a: &str, b: &str) -> U {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (len_a, len_b) = (a.len(), b.len());
+
+    if len_a == 0 {
+        return U::from(len_b);
+    } else if len_b == 0 {
+        return U::from(len_a);
+    }
+
+    // Keep only the previous-previous, previous, and current rows of the
+    // full DP table; the transposition candidate needs `pre_pre`.
+    let mut pre_pre: Vec<usize> = vec![0; len_b + 1];
+    let mut pre: Vec<usize> = (0..=len_b).collect();
+    let mut cur: Vec<usize> = vec![0; len_b + 1];
+
+    for i in 1..=len_a {
+        cur[0] = i;
+        for j in 1..=len_b {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut val = core::cmp::min(
+                // deletion
+                pre[j] + 1,
+                core::cmp::min(
+                    // insertion
+                    cur[j - 1] + 1,
+                    // match or substitution
+                    pre[j - 1] + cost,
+                ),
+            );
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                // transposition
+                val = core::cmp::min(val, pre_pre[j - 2] + 1);
+            }
+            cur[j] = val;
+        }
+        core::mem::swap(&mut pre_pre, &mut pre);
+        core::mem::swap(&mut pre, &mut cur);
+    }
+
+    U::from(pre[len_b])
+}
+
+/// Computes the (unrestricted) Damerau-Levenshtein distance between two strings.
+///
+/// Unlike the Optimal String Alignment distance, the true
+/// Damerau-Levenshtein distance places no restriction on editing a
+/// substring more than once, and so it is a true metric. It counts the
+/// minimum number of insertions, deletions, substitutions, and
+/// transpositions of adjacent characters needed to transform one string
+/// into the other.
+///
+/// We keep the full `(len_a + 1) x (len_b + 1)` matrix (stored with an
+/// extra sentinel row and column), together with a small table recording
+/// the last row in which each symbol was seen. For each cell we track
+/// `db`, the last column in row `i` at which `a[i-1] == b[j-1]`, and `k`,
+/// the last row in which symbol `b[j-1]` appeared; the transposition
+/// candidate is then `d[k-1][l-1] + (i-k-1) + 1 + (j-l-1)`.
+///
+/// The input strings are not required to be of the same length.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::damerau_levenshtein;
+///
+/// let distance: u16 = damerau_levenshtein("CA", "ABC");
+///
+/// assert_eq!(distance, 2);
+///
+/// let distance: u16 = damerau_levenshtein("ABC", "ACB");
+///
+/// assert_eq!(distance, 1);
+/// ```
+///
+/// # References
+///
+/// * [Damerau-Levenshtein distance](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance)
+///
+/// # Panics
+///
+/// * If the distance between `a` and `b` is too large to be represented by `U`.
+#[must_use]
+pub fn damerau_levenshtein<U: UInt>(a: &str, b: &str) -> U {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (len_a, len_b) = (a.len(), b.len());
+
+    if len_a == 0 {
+        return U::from(len_b);
+    } else if len_b == 0 {
+        return U::from(len_a);
+    }
+
+    let max_dist = len_a + len_b;
+
+    // The matrix carries an extra leading row and column (the sentinels
+    // `d[-1][*]` and `d[*][-1]`), so cell `d[i][j]` lives at `d[i + 1][j + 1]`.
+    let mut d = vec![vec![0usize; len_b + 2]; len_a + 2];
+    d[0][0] = max_dist;
+    for i in 0..=len_a {
+        d[i + 1][0] = max_dist;
+        d[i + 1][1] = i;
+    }
+    for j in 0..=len_b {
+        d[0][j + 1] = max_dist;
+        d[1][j + 1] = j;
+    }
+
+    // The last row (1-indexed) in which each seen symbol appeared.
+    let mut last_row: Vec<(char, usize)> = Vec::new();
+
+    for i in 1..=len_a {
+        // The last column in row `i` at which the characters matched.
+        let mut db = 0;
+        for j in 1..=len_b {
+            let k = last_row
+                .iter()
+                .find(|&&(c, _)| c == b[j - 1])
+                .map_or(0, |&(_, row)| row);
+            let l = db;
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            if cost == 0 {
+                db = j;
+            }
+            d[i + 1][j + 1] = core::cmp::min(
+                // substitution
+                d[i][j] + cost,
+                core::cmp::min(
+                    // insertion
+                    d[i + 1][j] + 1,
+                    core::cmp::min(
+                        // deletion
+                        d[i][j + 1] + 1,
+                        // transposition
+                        d[k][l] + (i - k - 1) + 1 + (j - l - 1),
+                    ),
+                ),
+            );
+        }
+        match last_row.iter_mut().find(|(c, _)| *c == a[i - 1]) {
+            Some((_, row)) => *row = i,
+            None => last_row.push((a[i - 1], i)),
+        }
+    }
+
+    U::from(d[len_a + 1][len_b + 1])
+}
+
 /// Computes the Hamming distance between two strings.
 ///
 /// The Hamming distance is defined as the number of positions at which
@@ -142,5 +501,507 @@ pub fn levenshtein<U: UInt>(a: &str, b: &str) -> U {
 /// * If the distance between `x` and `y` is too large to be represented by `U`.
 #[must_use]
 pub fn hamming<U: UInt>(x: &str, y: &str) -> U {
-    U::from(x.chars().zip(y.chars()).filter(|(a, b)| a != b).count())
+    let x: Vec<char> = x.chars().collect();
+    let y: Vec<char> = y.chars().collect();
+    hamming_seq(&x, &y)
+}
+
+/// Computes the Hamming distance between two sequences of comparable elements.
+///
+/// This is the generic core behind [`hamming`]; it works over slices of
+/// any `PartialEq` element, so callers can compute the distance over
+/// `&[u8]`, `&[u32]`, or arbitrary token slices without going through
+/// UTF-8.
+///
+/// As with [`hamming`], the sequences are not required to be of the same
+/// length, and the distance is only computed up to the length of the
+/// shorter one.
+///
+/// # Arguments
+///
+/// * `x` - The first sequence.
+/// * `y` - The second sequence.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::hamming_seq;
+///
+/// let x = [1_u32, 2, 3, 4];
+/// let y = [1_u32, 0, 3, 0];
+///
+/// let distance: u16 = hamming_seq(&x, &y);
+///
+/// assert_eq!(distance, 2);
+/// ```
+///
+/// # Panics
+///
+/// * If the distance between `x` and `y` is too large to be represented by `U`.
+#[must_use]
+pub fn hamming_seq<T: PartialEq, U: UInt>(x: &[T], y: &[T]) -> U {
+    U::from(x.iter().zip(y.iter()).filter(|(a, b)| a != b).count())
+}
+
+/// Computes the Jaro similarity between two strings.
+///
+/// The Jaro similarity is a value in `[0, 1]` that is better suited than
+/// edit distance to short strings such as human names, where typos
+/// manifest as a handful of matching and transposed characters. It is
+/// defined in terms of the number of matching characters `m` and the
+/// number of transpositions `t` (counted as half the number of matched
+/// characters that occur in a different order) as
+///
+/// ```text
+/// (m / |a| + m / |b| + (m - t) / m) / 3
+/// ```
+///
+/// or `0` when `m == 0`. Two characters match only if they are equal and
+/// lie within `floor(max(|a|, |b|) / 2) - 1` positions of one another.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::jaro;
+///
+/// let similarity: f64 = jaro("MARTHA", "MARHTA");
+///
+/// assert!((similarity - 0.944_444).abs() < 1e-6);
+/// ```
+///
+/// # References
+///
+/// * [Jaro-Winkler distance](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)
+#[must_use]
+pub fn jaro<F: Float>(a: &str, b: &str) -> F {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a == b {
+        return F::ONE;
+    }
+
+    let (len_a, len_b) = (a.len(), b.len());
+    if len_a == 0 || len_b == 0 {
+        return F::ZERO;
+    }
+
+    // The matching window either side of a given position.
+    let max_len = core::cmp::max(len_a, len_b);
+    let window = if max_len / 2 > 0 { max_len / 2 - 1 } else { 0 };
+
+    let mut a_match = vec![false; len_a];
+    let mut b_match = vec![false; len_b];
+    let mut matches = 0;
+
+    for (i, &ca) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = core::cmp::min(i + window + 1, len_b);
+        for j in lo..hi {
+            if !b_match[j] && ca == b[j] {
+                a_match[i] = true;
+                b_match[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return F::ZERO;
+    }
+
+    // Count transpositions by walking the matched characters in order.
+    let mut k = 0;
+    let mut mismatches = 0;
+    for (i, &ca) in a.iter().enumerate() {
+        if a_match[i] {
+            while !b_match[k] {
+                k += 1;
+            }
+            if ca != b[k] {
+                mismatches += 1;
+            }
+            k += 1;
+        }
+    }
+    let t = mismatches / 2;
+
+    let m = F::from(matches);
+    (m / F::from(len_a) + m / F::from(len_b) + (m - F::from(t)) / m) / F::from(3)
+}
+
+/// Computes the Jaro-Winkler similarity between two strings.
+///
+/// Jaro-Winkler refines the [`jaro`] similarity by rewarding strings that
+/// share a common prefix, which works well for human names where the
+/// first few characters are rarely mistyped. Given the Jaro similarity
+/// `j`, the common-prefix length `l` (capped at `4`), and a scaling factor
+/// `p` (here the customary `0.1`), the similarity is
+///
+/// ```text
+/// j + l * p * (1 - j)
+/// ```
+///
+/// The prefix bonus is only applied when `j` exceeds the boost threshold
+/// of `0.7`, matching the usual convention.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::jaro_winkler;
+///
+/// let similarity: f64 = jaro_winkler("MARTHA", "MARHTA");
+///
+/// assert!((similarity - 0.961_111).abs() < 1e-6);
+/// ```
+///
+/// # References
+///
+/// * [Jaro-Winkler distance](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)
+#[must_use]
+pub fn jaro_winkler<F: Float>(a: &str, b: &str) -> F {
+    let sim = jaro::<F>(a, b);
+
+    // Only boost reasonably similar strings.
+    if sim <= F::from(0.7) {
+        return sim;
+    }
+
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+
+    let p = F::from(0.1);
+    sim + F::from(prefix) * p * (F::ONE - sim)
+}
+
+/// A single operation in an edit script transforming one string into another.
+///
+/// The operations are those of the Levenshtein distance, with `Match`
+/// recording positions that are already equal so that the full script
+/// can be replayed against the source string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditOp {
+    /// The aligned characters are equal; no edit is required.
+    Match,
+    /// The source character `from` is replaced by the target character `to`.
+    Substitute {
+        /// The character in the source string.
+        from: char,
+        /// The character it is replaced with.
+        to: char,
+    },
+    /// The given character is inserted from the target string.
+    Insert(char),
+    /// The given character is deleted from the source string.
+    Delete(char),
+}
+
+/// Computes the Levenshtein distance together with an optimal edit script.
+///
+/// This returns both the scalar distance and a sequence of [`EditOp`]s
+/// that transform `a` into `b` with that many edits, which is what
+/// diffing and spell-correction callers need in order to show *which*
+/// insertions, deletions, and substitutions were applied.
+///
+/// We fill the full `(len_a + 1) x (len_b + 1)` Wagner-Fischer matrix and
+/// then backtrace from the bottom-right corner, at each step choosing the
+/// predecessor cell that produced the minimum, and finally reverse the
+/// collected operations so they read from the start of the strings.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::{levenshtein_alignment, EditOp};
+///
+/// let (distance, script): (u16, _) = levenshtein_alignment("ab", "acb");
+///
+/// assert_eq!(distance, 1);
+/// assert_eq!(
+///     script,
+///     vec![EditOp::Match, EditOp::Insert('c'), EditOp::Match],
+/// );
+/// ```
+///
+/// # References
+///
+/// * [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// # Panics
+///
+/// * If the distance between `a` and `b` is too large to be represented by `U`.
+#[must_use]
+pub fn levenshtein_alignment<U: UInt>(a: &str, b: &str) -> (U, Vec<EditOp>) {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (len_a, len_b) = (a.len(), b.len());
+
+    // Fill the full DP matrix so we can backtrace through it.
+    let mut d = vec![vec![0usize; len_b + 1]; len_a + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=len_b {
+        d[0][j] = j;
+    }
+    for i in 1..=len_a {
+        for j in 1..=len_b {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = core::cmp::min(
+                // deletion
+                d[i - 1][j] + 1,
+                core::cmp::min(
+                    // insertion
+                    d[i][j - 1] + 1,
+                    // match or substitution
+                    d[i - 1][j - 1] + cost,
+                ),
+            );
+        }
+    }
+
+    // Backtrace from the bottom-right corner, preferring diagonal moves.
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (len_a, len_b);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && d[i][j] == d[i - 1][j - 1] {
+            ops.push(EditOp::Match);
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && d[i][j] == d[i - 1][j - 1] + 1 {
+            ops.push(EditOp::Substitute {
+                from: a[i - 1],
+                to: b[j - 1],
+            });
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+            ops.push(EditOp::Delete(a[i - 1]));
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insert(b[j - 1]));
+            j -= 1;
+        }
+    }
+    ops.reverse();
+
+    (U::from(d[len_a][len_b]), ops)
+}
+
+/// The per-operation penalties used by [`levenshtein_weighted`].
+///
+/// Many applications need distinct costs for the three edit operations:
+/// OCR confusion matrices, keyboard-distance typo models, and biological
+/// indel penalties all weight insertions, deletions, and substitutions
+/// differently. The unit-cost `EditCosts { insert: 1, delete: 1,
+/// substitute: 1 }` recovers the plain [`levenshtein`] distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditCosts<U: UInt> {
+    /// The cost of inserting a character.
+    pub insert: U,
+    /// The cost of deleting a character.
+    pub delete: U,
+    /// The cost of substituting one character for another.
+    pub substitute: U,
+}
+
+impl<U: UInt> EditCosts<U> {
+    /// Creates a new set of edit costs.
+    #[must_use]
+    pub const fn new(insert: U, delete: U, substitute: U) -> Self {
+        Self {
+            insert,
+            delete,
+            substitute,
+        }
+    }
+}
+
+/// Computes the Levenshtein distance using configurable edit-operation costs.
+///
+/// This generalizes the Wagner-Fischer recurrence so each branch adds its
+/// respective cost from `costs` instead of a flat `1`; a substitution
+/// still contributes `0` when the characters match. Passing unit costs
+/// recovers the plain [`levenshtein`] distance.
+///
+/// Unlike [`levenshtein`], this function never swaps its arguments to put
+/// the longer string first: that optimization is only valid when the
+/// insertion and deletion costs are equal, and is otherwise asymmetric.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+/// * `costs` - The per-operation penalties.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::{levenshtein_weighted, EditCosts};
+///
+/// let costs = EditCosts::new(1_u16, 1, 2);
+///
+/// let distance: u16 = levenshtein_weighted("ab", "ac", &costs);
+///
+/// assert_eq!(distance, 2);
+/// ```
+///
+/// # References
+///
+/// * [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+///
+/// # Panics
+///
+/// * If the distance between `a` and `b` is too large to be represented by `U`.
+#[must_use]
+pub fn levenshtein_weighted<U: UInt>(a: &str, b: &str, costs: &EditCosts<U>) -> U {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let len_b = b.len();
+
+    // initialize the first row: inserting the first `j` characters of `b`
+    let mut cur: Vec<U> = Vec::with_capacity(len_b + 1);
+    cur.push(U::ZERO);
+    for j in 1..=len_b {
+        cur.push(cur[j - 1] + costs.insert);
+    }
+
+    for ca in &a {
+        // first column for this row: deleting the first `i` characters of `a`
+        let mut pre = cur[0];
+        cur[0] = pre + costs.delete;
+        for (j, cb) in b.iter().enumerate() {
+            let tmp = cur[j + 1];
+            let sub = pre
+                + if ca == cb {
+                    U::ZERO
+                } else {
+                    costs.substitute
+                };
+            cur[j + 1] = core::cmp::min(
+                // deletion
+                tmp + costs.delete,
+                core::cmp::min(
+                    // insertion
+                    cur[j] + costs.insert,
+                    // match or substitution
+                    sub,
+                ),
+            );
+            pre = tmp;
+        }
+    }
+
+    cur[len_b]
+}
+
+/// Computes a normalized Levenshtein similarity in `[0, 1]`.
+///
+/// The string metrics return raw edit counts, which are awkward to
+/// threshold or combine with the normalized set metrics. This wrapper
+/// rescales the [`levenshtein`] distance as `1 - distance / max_len`,
+/// where `max_len` is the length of the longer string, matching the
+/// convention used by `rapidfuzz`. Two empty strings are maximally
+/// similar and return `1.0`.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::levenshtein_normalized;
+///
+/// let similarity: f64 = levenshtein_normalized("kitten", "sitting");
+///
+/// assert!((similarity - 0.571_428).abs() < 1e-6);
+/// ```
+///
+/// # References
+///
+/// * [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+#[must_use]
+pub fn levenshtein_normalized<F: Float>(a: &str, b: &str) -> F {
+    let max_len = core::cmp::max(a.chars().count(), b.chars().count());
+    if max_len == 0 {
+        // two empty strings are identical
+        return F::ONE;
+    }
+
+    let distance: usize = levenshtein(a, b);
+    let similarity = F::ONE - F::from(distance) / F::from(max_len);
+    clamp_unit(similarity)
+}
+
+/// Computes a normalized Hamming similarity in `[0, 1]`.
+///
+/// This rescales the [`hamming`] distance as `1 - distance / max_len`,
+/// where `max_len` is the length of the longer string so that a trailing
+/// length difference counts as mismatched positions. Two empty strings
+/// return `1.0`.
+///
+/// # Arguments
+///
+/// * `x` - The first string.
+/// * `y` - The second string.
+///
+/// # Examples
+///
+/// ```
+/// use distances::strings::hamming_normalized;
+///
+/// let similarity: f64 = hamming_normalized("karolin", "kathrin");
+///
+/// assert!((similarity - 0.571_428).abs() < 1e-6);
+/// ```
+///
+/// # References
+///
+/// * [Hamming distance](https://en.wikipedia.org/wiki/Hamming_distance)
+#[must_use]
+pub fn hamming_normalized<F: Float>(x: &str, y: &str) -> F {
+    let (len_x, len_y) = (x.chars().count(), y.chars().count());
+    let max_len = core::cmp::max(len_x, len_y);
+    if max_len == 0 {
+        // two empty strings are identical
+        return F::ONE;
+    }
+
+    // Positions beyond the shorter string count as mismatches.
+    let mismatches = x.chars().zip(y.chars()).filter(|(a, b)| a != b).count();
+    let distance = mismatches + len_x.abs_diff(len_y);
+    let similarity = F::ONE - F::from(distance) / F::from(max_len);
+    clamp_unit(similarity)
+}
+
+/// Clamps a similarity to the closed interval `[0, 1]`.
+fn clamp_unit<F: Float>(value: F) -> F {
+    if value < F::ZERO {
+        F::ZERO
+    } else if value > F::ONE {
+        F::ONE
+    } else {
+        value
+    }
 }